@@ -1,10 +1,13 @@
 //! Working with byte slices that have an associated endianity.
 
-use endianity::Endianity;
-use std::mem;
-use std::ops::{Deref, Index, Range, RangeFrom, RangeTo};
-use std::str;
+use byteorder;
+use endianity::{Endianity, FromEndian};
+use core::mem;
+use core::ops::{Deref, Index, Range, RangeFrom, RangeTo};
+use core::str;
+#[cfg(feature = "alloc")]
 use string::String;
+#[cfg(feature = "alloc")]
 use borrow::Cow;
 use parser::{Error, Result};
 use reader::Reader;
@@ -78,6 +81,7 @@ where
 
     /// Converts the slice to a string, including invalid characters,
     /// using `String::from_utf8_lossy`.
+    #[cfg(feature = "alloc")]
     #[inline]
     pub fn to_string_lossy(&self) -> Cow<'input, str> {
         String::from_utf8_lossy(self.slice)
@@ -158,6 +162,88 @@ where
             endian: self.endian,
         }
     }
+
+    /// Take the given `start..end` range of the underlying slice and return a
+    /// new `EndianSlice`, or `Err(Error::UnexpectedEof)` if the range is out
+    /// of bounds, rather than panicking.
+    ///
+    /// ```
+    /// use gimli::{EndianSlice, LittleEndian};
+    ///
+    /// let slice = &[0x01, 0x02, 0x03, 0x04];
+    /// let endian_slice = EndianSlice::new(slice, LittleEndian);
+    /// assert!(endian_slice.get_range(1..30).is_err());
+    /// ```
+    pub fn get_range(&self, idx: Range<usize>) -> Result<EndianSlice<'input, Endian>> {
+        self.slice
+            .get(idx)
+            .map(|slice| EndianSlice {
+                slice,
+                endian: self.endian,
+            })
+            .ok_or(Error::UnexpectedEof)
+    }
+
+    /// Take the given `start..` range of the underlying slice and return a
+    /// new `EndianSlice`, or `Err(Error::UnexpectedEof)` if `start` is out of
+    /// bounds, rather than panicking.
+    ///
+    /// ```
+    /// use gimli::{EndianSlice, LittleEndian};
+    ///
+    /// let slice = &[0x01, 0x02, 0x03, 0x04];
+    /// let endian_slice = EndianSlice::new(slice, LittleEndian);
+    /// assert_eq!(endian_slice.get_range_from(2..),
+    ///            Ok(EndianSlice::new(&slice[2..], LittleEndian)));
+    /// assert!(endian_slice.get_range_from(30..).is_err());
+    /// ```
+    pub fn get_range_from(&self, idx: RangeFrom<usize>) -> Result<EndianSlice<'input, Endian>> {
+        self.slice
+            .get(idx)
+            .map(|slice| EndianSlice {
+                slice,
+                endian: self.endian,
+            })
+            .ok_or(Error::UnexpectedEof)
+    }
+
+    /// Take the given `..end` range of the underlying slice and return a new
+    /// `EndianSlice`, or `Err(Error::UnexpectedEof)` if `end` is out of
+    /// bounds, rather than panicking.
+    ///
+    /// ```
+    /// use gimli::{EndianSlice, LittleEndian};
+    ///
+    /// let slice = &[0x01, 0x02, 0x03, 0x04];
+    /// let endian_slice = EndianSlice::new(slice, LittleEndian);
+    /// assert_eq!(endian_slice.get_range_to(..3),
+    ///            Ok(EndianSlice::new(&slice[..3], LittleEndian)));
+    /// assert!(endian_slice.get_range_to(..30).is_err());
+    /// ```
+    pub fn get_range_to(&self, idx: RangeTo<usize>) -> Result<EndianSlice<'input, Endian>> {
+        self.slice
+            .get(idx)
+            .map(|slice| EndianSlice {
+                slice,
+                endian: self.endian,
+            })
+            .ok_or(Error::UnexpectedEof)
+    }
+
+    /// Return the byte at the given index, or `Err(Error::UnexpectedEof)` if
+    /// the index is out of bounds, rather than panicking.
+    ///
+    /// ```
+    /// use gimli::{EndianSlice, LittleEndian};
+    ///
+    /// let slice = &[0x01, 0x02, 0x03, 0x04];
+    /// let endian_slice = EndianSlice::new(slice, LittleEndian);
+    /// assert_eq!(endian_slice.get(1), Ok(0x02));
+    /// assert!(endian_slice.get(30).is_err());
+    /// ```
+    pub fn get(&self, idx: usize) -> Result<u8> {
+        self.slice.get(idx).cloned().ok_or(Error::UnexpectedEof)
+    }
 }
 
 impl<'input, Endian> Index<usize> for EndianSlice<'input, Endian>
@@ -262,11 +348,13 @@ where
         Ok(EndianSlice::new(slice, self.endian))
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn to_slice(&self) -> Result<Cow<[u8]>> {
         Ok(self.slice.into())
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn to_string(&self) -> Result<Cow<str>> {
         match str::from_utf8(self.slice) {
@@ -275,6 +363,7 @@ where
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn to_string_lossy(&self) -> Result<Cow<str>> {
         Ok(String::from_utf8_lossy(self.slice))
@@ -306,38 +395,101 @@ where
 
     #[inline]
     fn read_u16(&mut self) -> Result<u16> {
-        let slice = self.read_slice(2)?;
-        Ok(self.endian.read_u16(slice))
+        self.read_endian()
     }
 
     #[inline]
     fn read_i16(&mut self) -> Result<i16> {
-        let slice = self.read_slice(2)?;
-        Ok(self.endian.read_i16(slice))
+        self.read_endian()
     }
 
     #[inline]
     fn read_u32(&mut self) -> Result<u32> {
-        let slice = self.read_slice(4)?;
-        Ok(self.endian.read_u32(slice))
+        self.read_endian()
     }
 
     #[inline]
     fn read_i32(&mut self) -> Result<i32> {
-        let slice = self.read_slice(4)?;
-        Ok(self.endian.read_i32(slice))
+        self.read_endian()
     }
 
     #[inline]
     fn read_u64(&mut self) -> Result<u64> {
-        let slice = self.read_slice(8)?;
-        Ok(self.endian.read_u64(slice))
+        self.read_endian()
     }
 
     #[inline]
     fn read_i64(&mut self) -> Result<i64> {
-        let slice = self.read_slice(8)?;
-        Ok(self.endian.read_i64(slice))
+        self.read_endian()
+    }
+
+    /// Read a single endian-dependent primitive value, dispatching on the
+    /// slice's runtime or compile-time endianity.
+    ///
+    /// This overrides `Reader::read_endian`'s default byte-at-a-time loop
+    /// with a single bulk read, since `EndianSlice` can slice its backing
+    /// `&[u8]` directly.
+    #[inline]
+    fn read_endian<T: FromEndian>(&mut self) -> Result<T> {
+        let slice = self.read_slice(T::N)?;
+        Ok(if self.endian.is_big_endian() {
+            T::from_be_bytes(slice)
+        } else {
+            T::from_le_bytes(slice)
+        })
+    }
+}
+
+impl<'input, Endian> EndianSlice<'input, Endian>
+where
+    Endian: Endianity,
+{
+    /// Read `dst.len()` unsigned 16 bit integers into `dst`.
+    ///
+    /// On the common case where the slice's endianity matches the target's
+    /// native endianity, this copies the underlying bytes directly into
+    /// `dst` rather than branching on endianity once per element.
+    #[inline]
+    pub fn read_u16_into(&mut self, dst: &mut [u16]) -> Result<()> {
+        let slice = self.read_slice(dst.len() * 2)?;
+        if self.endian.is_big_endian() {
+            byteorder::BigEndian::read_u16_into(slice, dst);
+        } else {
+            byteorder::LittleEndian::read_u16_into(slice, dst);
+        }
+        Ok(())
+    }
+
+    /// Read `dst.len()` unsigned 32 bit integers into `dst`.
+    ///
+    /// On the common case where the slice's endianity matches the target's
+    /// native endianity, this copies the underlying bytes directly into
+    /// `dst` rather than branching on endianity once per element.
+    #[inline]
+    pub fn read_u32_into(&mut self, dst: &mut [u32]) -> Result<()> {
+        let slice = self.read_slice(dst.len() * 4)?;
+        if self.endian.is_big_endian() {
+            byteorder::BigEndian::read_u32_into(slice, dst);
+        } else {
+            byteorder::LittleEndian::read_u32_into(slice, dst);
+        }
+        Ok(())
+    }
+
+    /// Read `dst.len()` unsigned 64 bit integers into `dst`.
+    ///
+    /// On the common case where the slice's endianity matches the target's
+    /// native endianity, this copies the underlying bytes directly into
+    /// `dst` rather than branching on endianity once per element.
+    #[inline]
+    pub fn read_u64_into(&mut self, dst: &mut [u64]) -> Result<()> {
+        let slice = self.read_slice(dst.len() * 8)?;
+        if self.endian.is_big_endian() {
+            byteorder::BigEndian::read_u64_into(slice, dst);
+        } else {
+            byteorder::LittleEndian::read_u64_into(slice, dst);
+        }
+        Ok(())
     }
 }
 
@@ -367,4 +519,100 @@ mod tests {
         let eb = EndianSlice::new(slice, NativeEndian);
         eb.split_at(30);
     }
+
+    #[test]
+    fn test_endian_slice_get_range() {
+        let endian = NativeEndian;
+        let slice = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0];
+        let eb = EndianSlice::new(slice, endian);
+        assert_eq!(
+            eb.get_range(1..3),
+            Ok(EndianSlice::new(&slice[1..3], endian))
+        );
+        assert!(eb.get_range(1..30).is_err());
+        assert_eq!(
+            eb.get_range_from(2..),
+            Ok(EndianSlice::new(&slice[2..], endian))
+        );
+        assert!(eb.get_range_from(30..).is_err());
+        assert_eq!(
+            eb.get_range_to(..3),
+            Ok(EndianSlice::new(&slice[..3], endian))
+        );
+        assert!(eb.get_range_to(..30).is_err());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u16_into() {
+        let slice = &[0x01, 0x00, 0x02, 0x00];
+        let mut eb = EndianSlice::new(slice, ::endianity::LittleEndian);
+        let mut dst = [0u16; 2];
+        eb.read_u16_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u16_into_big_endian() {
+        let slice = &[0x00, 0x01, 0x00, 0x02];
+        let mut eb = EndianSlice::new(slice, ::endianity::BigEndian);
+        let mut dst = [0u16; 2];
+        eb.read_u16_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u32_into() {
+        let slice = &[0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+        let mut eb = EndianSlice::new(slice, ::endianity::LittleEndian);
+        let mut dst = [0u32; 2];
+        eb.read_u32_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u32_into_big_endian() {
+        let slice = &[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+        let mut eb = EndianSlice::new(slice, ::endianity::BigEndian);
+        let mut dst = [0u32; 2];
+        eb.read_u32_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u64_into() {
+        let slice = &[
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let mut eb = EndianSlice::new(slice, ::endianity::LittleEndian);
+        let mut dst = [0u64; 2];
+        eb.read_u64_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_read_u64_into_big_endian() {
+        let slice = &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02,
+        ];
+        let mut eb = EndianSlice::new(slice, ::endianity::BigEndian);
+        let mut dst = [0u64; 2];
+        eb.read_u64_into(&mut dst).unwrap();
+        assert_eq!(dst, [1, 2]);
+        assert!(eb.is_empty());
+    }
+
+    #[test]
+    fn test_endian_slice_get() {
+        let slice = &[1, 2, 3];
+        let eb = EndianSlice::new(slice, NativeEndian);
+        assert_eq!(eb.get(1), Ok(2));
+        assert!(eb.get(30).is_err());
+    }
 }
@@ -2,7 +2,7 @@
 
 use byteorder;
 use byteorder::ByteOrder;
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 /// A trait describing the endianity of some buffer.
 pub trait Endianity: Debug + Default + Clone + Copy + PartialEq + Eq {
@@ -87,6 +87,34 @@ pub trait Endianity: Debug + Default + Clone + Copy + PartialEq + Eq {
         self.read_u64(buf) as i64
     }
 
+    /// Writes an unsigned 16 bit integer `n` to `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `buf.len() < 2`.
+    #[inline]
+    fn write_u16(self, buf: &mut [u8], n: u16) {
+        if self.is_big_endian() {
+            byteorder::BigEndian::write_u16(buf, n)
+        } else {
+            byteorder::LittleEndian::write_u16(buf, n)
+        }
+    }
+
+    /// Writes an unsigned 32 bit integer `n` to `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `buf.len() < 4`.
+    #[inline]
+    fn write_u32(self, buf: &mut [u8], n: u32) {
+        if self.is_big_endian() {
+            byteorder::BigEndian::write_u32(buf, n)
+        } else {
+            byteorder::LittleEndian::write_u32(buf, n)
+        }
+    }
+
     /// Writes an unsigned 64 bit integer `n` to `buf`.
     ///
     /// # Panics
@@ -100,8 +128,112 @@ pub trait Endianity: Debug + Default + Clone + Copy + PartialEq + Eq {
             byteorder::LittleEndian::write_u64(buf, n)
         }
     }
+
+    /// Writes a signed 16 bit integer `n` to `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `buf.len() < 2`.
+    #[inline]
+    fn write_i16(self, buf: &mut [u8], n: i16) {
+        self.write_u16(buf, n as u16)
+    }
+
+    /// Writes a signed 32 bit integer `n` to `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `buf.len() < 4`.
+    #[inline]
+    fn write_i32(self, buf: &mut [u8], n: i32) {
+        self.write_u32(buf, n as u32)
+    }
+
+    /// Writes a signed 64 bit integer `n` to `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `buf.len() < 8`.
+    #[inline]
+    fn write_i64(self, buf: &mut [u8], n: i64) {
+        self.write_u64(buf, n as u64)
+    }
+}
+
+/// A value that can be constructed from a fixed-size, endian-dependent byte
+/// array.
+///
+/// This is implemented for the 16, 32 and 64 bit integer types so that
+/// `Reader::read_endian` can dispatch on a single generic method instead of
+/// a hand-rolled `read_uN`/`read_iN` pair for every width.
+pub trait FromEndian: Sized {
+    /// The number of bytes needed to represent `Self`.
+    const N: usize;
+
+    /// Construct `Self` from `N` little-endian bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `bytes.len() < Self::N`.
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Construct `Self` from `N` big-endian bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `bytes.len() < Self::N`.
+    fn from_be_bytes(bytes: &[u8]) -> Self;
 }
 
+macro_rules! from_endian_int {
+    ($ty:ty, $read_le:expr, $read_be:expr) => {
+        impl FromEndian for $ty {
+            const N: usize = ::core::mem::size_of::<$ty>();
+
+            #[inline]
+            fn from_le_bytes(bytes: &[u8]) -> Self {
+                $read_le(bytes)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: &[u8]) -> Self {
+                $read_be(bytes)
+            }
+        }
+    };
+}
+
+from_endian_int!(
+    u16,
+    byteorder::LittleEndian::read_u16,
+    byteorder::BigEndian::read_u16
+);
+from_endian_int!(
+    u32,
+    byteorder::LittleEndian::read_u32,
+    byteorder::BigEndian::read_u32
+);
+from_endian_int!(
+    u64,
+    byteorder::LittleEndian::read_u64,
+    byteorder::BigEndian::read_u64
+);
+from_endian_int!(
+    i16,
+    byteorder::LittleEndian::read_i16,
+    byteorder::BigEndian::read_i16
+);
+from_endian_int!(
+    i32,
+    byteorder::LittleEndian::read_i32,
+    byteorder::BigEndian::read_i32
+);
+from_endian_int!(
+    i64,
+    byteorder::LittleEndian::read_i64,
+    byteorder::BigEndian::read_i64
+);
+
 /// Byte order that is selectable at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RunTimeEndian {
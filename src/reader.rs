@@ -0,0 +1,128 @@
+//! Reading bytes with an associated endianity.
+
+use core::fmt::Debug;
+
+#[cfg(feature = "alloc")]
+use borrow::Cow;
+
+use endianity::{Endianity, FromEndian};
+use parser::Result;
+
+/// A trait for reading bytes out of an underlying source, keeping track of
+/// the read offset and endianity along the way.
+///
+/// `EndianSlice` is the canonical implementation of this trait, but it also
+/// allows for other read sources, such as a `Vec<u8>` combined with a
+/// separate read cursor.
+pub trait Reader: Debug + Clone {
+    /// The endianity of bytes that this reader reads.
+    type Endian: Endianity;
+
+    /// The type used for offsets and lengths.
+    type Offset: Debug + Clone;
+
+    /// Return the endianity of this reader.
+    fn endian(&self) -> Self::Endian;
+
+    /// Return the number of bytes remaining.
+    fn len(&self) -> Self::Offset;
+
+    /// Return true if there are no bytes remaining.
+    fn is_empty(&self) -> bool;
+
+    /// Set the number of bytes remaining to zero.
+    fn empty(&mut self);
+
+    /// Truncate this reader to the given length.
+    fn truncate(&mut self, len: Self::Offset) -> Result<()>;
+
+    /// Return this reader's offset relative to `base`'s offset.
+    fn offset_from(&self, base: &Self) -> Self::Offset;
+
+    /// Find the first occurrence of a byte in this reader, and return its
+    /// index.
+    fn find(&self, byte: u8) -> Result<Self::Offset>;
+
+    /// Discard `len` bytes from the front of this reader.
+    fn skip(&mut self, len: Self::Offset) -> Result<()>;
+
+    /// Split off and return the first `len` bytes, advancing this reader
+    /// past them.
+    fn split(&mut self, len: Self::Offset) -> Result<Self>;
+
+    /// Return the remaining bytes as a `Cow<[u8]>`, cloning only if
+    /// necessary.
+    ///
+    /// This requires allocation support, since an owned fallback needs a
+    /// `Vec<u8>` to clone into when the underlying reader isn't already a
+    /// borrowed byte slice.
+    #[cfg(feature = "alloc")]
+    fn to_slice(&self) -> Result<Cow<[u8]>>;
+
+    /// Interpret the remaining bytes as a UTF-8 string, and return it as a
+    /// `Cow<str>`, cloning only if necessary.
+    ///
+    /// This requires allocation support for the same reason as `to_slice`.
+    #[cfg(feature = "alloc")]
+    fn to_string(&self) -> Result<Cow<str>>;
+
+    /// Interpret the remaining bytes as a UTF-8 string, including invalid
+    /// characters, and return it as a `Cow<str>`, cloning only if necessary.
+    ///
+    /// This requires allocation support for the same reason as `to_slice`.
+    #[cfg(feature = "alloc")]
+    fn to_string_lossy(&self) -> Result<Cow<str>>;
+
+    /// Read exactly `size_of::<A>()` bytes into a fixed-size array.
+    fn read_u8_array<A>(&mut self) -> Result<A>
+    where
+        A: Sized + Default + AsMut<[u8]>;
+
+    /// Read an unsigned 8 bit integer.
+    fn read_u8(&mut self) -> Result<u8>;
+
+    /// Read a signed 8 bit integer.
+    fn read_i8(&mut self) -> Result<i8>;
+
+    /// Read an unsigned 16 bit integer.
+    fn read_u16(&mut self) -> Result<u16>;
+
+    /// Read a signed 16 bit integer.
+    fn read_i16(&mut self) -> Result<i16>;
+
+    /// Read an unsigned 32 bit integer.
+    fn read_u32(&mut self) -> Result<u32>;
+
+    /// Read a signed 32 bit integer.
+    fn read_i32(&mut self) -> Result<i32>;
+
+    /// Read an unsigned 64 bit integer.
+    fn read_u64(&mut self) -> Result<u64>;
+
+    /// Read a signed 64 bit integer.
+    fn read_i64(&mut self) -> Result<i64>;
+
+    /// Read a single endian-dependent primitive value, dispatching on this
+    /// reader's endianity.
+    ///
+    /// This is the generic core that `read_u16`, `read_i16`, `read_u32`, and
+    /// friends can be implemented in terms of. Those named methods have no
+    /// default body, so each implementor still provides them, typically as a
+    /// one-line call to this method. The default implementation reads `T::N` bytes one
+    /// at a time through `read_u8`; implementors that can slice their
+    /// underlying storage directly (like `EndianSlice`) should override this
+    /// with a bulk read for speed.
+    #[inline]
+    fn read_endian<T: FromEndian>(&mut self) -> Result<T> {
+        let mut buf = [0u8; 8];
+        let buf = &mut buf[..T::N];
+        for byte in buf.iter_mut() {
+            *byte = self.read_u8()?;
+        }
+        Ok(if self.endian().is_big_endian() {
+            T::from_be_bytes(buf)
+        } else {
+            T::from_le_bytes(buf)
+        })
+    }
+}
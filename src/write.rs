@@ -0,0 +1,220 @@
+//! Writing bytes with an associated endianity.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use endianity::Endianity;
+use parser::{Error, Result};
+
+/// A trait for writing bytes and endian-aware primitives, the dual of
+/// `Reader`.
+///
+/// Implementors track a current write offset the same way `Reader`
+/// implementors track a current read offset.
+pub trait Writer: Clone {
+    /// The endianity that primitives are written with.
+    type Endian: Endianity;
+
+    /// Return the endianity that this writer uses.
+    fn endian(&self) -> Self::Endian;
+
+    /// Return the number of bytes written so far.
+    fn len(&self) -> usize;
+
+    /// Return `true` if no bytes have been written yet.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write a single byte.
+    fn write_u8(&mut self, value: u8) -> Result<()>;
+
+    /// Write a slice of bytes, in order, without any endian conversion.
+    fn write_slice(&mut self, bytes: &[u8]) -> Result<()>;
+
+    /// Overwrite `bytes` at an already-written `offset`, without changing
+    /// `len()`.
+    ///
+    /// This is how a placeholder (e.g. a section length or a relocated
+    /// offset) gets patched in once its final value is known, after the
+    /// body that follows it has already been written. `offset + bytes.len()`
+    /// must not exceed `len()`.
+    ///
+    /// The default implementation returns `Err(Error::UnexpectedEof)` for
+    /// writers that only ever append; implementors that can patch already-
+    /// written bytes should override it.
+    #[inline]
+    fn write_at(&mut self, _offset: usize, _bytes: &[u8]) -> Result<()> {
+        Err(Error::UnexpectedEof)
+    }
+
+    /// Write an unsigned 16 bit integer using this writer's endianity.
+    #[inline]
+    fn write_u16(&mut self, value: u16) -> Result<()> {
+        let mut buf = [0; 2];
+        self.endian().write_u16(&mut buf, value);
+        self.write_slice(&buf)
+    }
+
+    /// Write an unsigned 32 bit integer using this writer's endianity.
+    #[inline]
+    fn write_u32(&mut self, value: u32) -> Result<()> {
+        let mut buf = [0; 4];
+        self.endian().write_u32(&mut buf, value);
+        self.write_slice(&buf)
+    }
+
+    /// Write an unsigned 64 bit integer using this writer's endianity.
+    #[inline]
+    fn write_u64(&mut self, value: u64) -> Result<()> {
+        let mut buf = [0; 8];
+        self.endian().write_u64(&mut buf, value);
+        self.write_slice(&buf)
+    }
+
+    /// Write a signed 16 bit integer using this writer's endianity.
+    #[inline]
+    fn write_i16(&mut self, value: i16) -> Result<()> {
+        self.write_u16(value as u16)
+    }
+
+    /// Write a signed 32 bit integer using this writer's endianity.
+    #[inline]
+    fn write_i32(&mut self, value: i32) -> Result<()> {
+        self.write_u32(value as u32)
+    }
+
+    /// Write a signed 64 bit integer using this writer's endianity.
+    #[inline]
+    fn write_i64(&mut self, value: i64) -> Result<()> {
+        self.write_u64(value as u64)
+    }
+}
+
+/// An owned, endian-aware byte buffer, backed by a `Vec<u8>`.
+///
+/// This is the natural producer counterpart to `EndianSlice`: callers that
+/// need to emit DWARF (relocation, section rebuilding, test fixtures) push
+/// primitives onto an `EndianVec` instead of hand-assembling byte buffers
+/// and branching on endianity themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndianVec<Endian>
+where
+    Endian: Endianity,
+{
+    vec: Vec<u8>,
+    endian: Endian,
+}
+
+impl<Endian> EndianVec<Endian>
+where
+    Endian: Endianity,
+{
+    /// Construct an empty `EndianVec` with the given endianity.
+    #[inline]
+    pub fn new(endian: Endian) -> EndianVec<Endian> {
+        EndianVec {
+            vec: Vec::new(),
+            endian,
+        }
+    }
+
+    /// Return a reference to the bytes written so far.
+    #[inline]
+    pub fn slice(&self) -> &[u8] {
+        &self.vec
+    }
+
+    /// Consume this `EndianVec`, returning the written bytes.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.vec
+    }
+}
+
+impl<Endian> Writer for EndianVec<Endian>
+where
+    Endian: Endianity,
+{
+    type Endian = Endian;
+
+    #[inline]
+    fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.vec.push(value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        self.vec.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        let end = offset.checked_add(bytes.len()).ok_or(Error::UnexpectedEof)?;
+        if end > self.vec.len() {
+            return Err(Error::UnexpectedEof);
+        }
+        self.vec[offset..end].copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use endianity::LittleEndian;
+
+    #[test]
+    fn test_endian_vec_write_u32() {
+        let mut w = EndianVec::new(LittleEndian);
+        w.write_u32(0x0102_0304).unwrap();
+        assert_eq!(w.slice(), &[0x04, 0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn test_endian_vec_write_slice() {
+        let mut w = EndianVec::new(LittleEndian);
+        w.write_u8(0xff).unwrap();
+        w.write_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(w.slice(), &[0xff, 1, 2, 3]);
+        assert_eq!(w.len(), 4);
+    }
+
+    #[test]
+    fn test_endian_vec_write_at() {
+        // Emit a placeholder length field, then a variable-length body, then
+        // patch the placeholder once the final length is known.
+        let mut w = EndianVec::new(LittleEndian);
+        let length_offset = w.len();
+        w.write_u32(0).unwrap();
+        let body_offset = w.len();
+        w.write_slice(&[1, 2, 3]).unwrap();
+        let body_len = (w.len() - body_offset) as u32;
+        let mut length_bytes = [0; 4];
+        w.endian().write_u32(&mut length_bytes, body_len);
+        w.write_at(length_offset, &length_bytes).unwrap();
+        assert_eq!(w.slice(), &[3, 0, 0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_endian_vec_write_at_out_of_bounds() {
+        let mut w = EndianVec::new(LittleEndian);
+        w.write_u8(1).unwrap();
+        assert!(w.write_at(0, &[1, 2]).is_err());
+    }
+}